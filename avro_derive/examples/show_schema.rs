@@ -0,0 +1,27 @@
+//! Sample consumer driving `#[derive(AvroSchema)]` through the public export.
+#![allow(dead_code)] // fields exist for their types; the derive reads them, main does not
+
+extern crate alloc;
+
+use avro_derive::AvroSchema;
+
+#[derive(AvroSchema)]
+#[avro(namespace = "com.example")]
+struct Inner {
+	count: i64,
+}
+
+#[derive(AvroSchema)]
+#[avro(namespace = "com.example")]
+struct Outer {
+	#[avro(rename = "full_name")]
+	name: String,
+	payload: Vec<u8>,
+	inner: Inner,
+	next: Option<Box<Outer>>,
+}
+
+fn main() {
+	let schema = Outer::get_schema();
+	println!("{}", schema.canonical_form());
+}
@@ -0,0 +1,248 @@
+//! Derive macro generating an Avro schema from a Rust type.
+//!
+//! `#[derive(AvroSchema)]` adds an inherent `get_schema() -> apache_avro::schema::Schema`
+//! to structs and unit-variant enums so their schema can be fed straight into the
+//! canonical-form and fingerprinting path of the companion crate instead of
+//! hand-writing JSON. The container attribute `#[avro(namespace = "...")]` sets the
+//! schema namespace and `#[avro(rename = "...")]` renames a field in the generated
+//! schema. Nested types are emitted by reusing their own `get_schema()`, and a field
+//! whose type is the enclosing type is emitted as a named reference so recursive types
+//! resolve.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{
+	parse_macro_input, Data, DeriveInput, Fields, GenericArgument, Lit, PathArguments, Type,
+};
+
+/// Derives an Avro schema for the annotated type.
+#[proc_macro_derive(AvroSchema, attributes(avro))]
+pub fn derive_avro_schema(input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as DeriveInput);
+	let name = input.ident.clone();
+	let schema_name = name.to_string();
+	let namespace = container_namespace(&input);
+	let fullname = match &namespace {
+		Some(ns) => format!("{}.{}", ns, schema_name),
+		None => schema_name.clone(),
+	};
+
+	let body = match &input.data {
+		Data::Struct(data) => record_json_expr(&schema_name, &namespace, &fullname, &data.fields),
+		Data::Enum(data) => enum_json_expr(&schema_name, &namespace, data),
+		Data::Union(_) =>
+			return syn::Error::new_spanned(&input, "AvroSchema cannot be derived for unions")
+				.to_compile_error()
+				.into(),
+	};
+
+	let expanded = quote! {
+		impl #name {
+			/// Returns the Avro schema generated for this type.
+			pub fn get_schema() -> apache_avro::schema::Schema {
+				let json: ::alloc::string::String = #body;
+				apache_avro::schema::Schema::parse_str(&json)
+					.expect("derived Avro schema should be valid")
+			}
+		}
+	};
+	expanded.into()
+}
+
+/// Reads the `#[avro(namespace = "...")]` container attribute, if present.
+fn container_namespace(input: &DeriveInput) -> Option<String> {
+	string_attr(&input.attrs, "namespace")
+}
+
+/// Builds the JSON expression for a record schema.
+fn record_json_expr(
+	name: &str,
+	namespace: &Option<String>,
+	fullname: &str,
+	fields: &Fields,
+) -> proc_macro2::TokenStream {
+	let named = match fields {
+		Fields::Named(named) => &named.named,
+		_ => {
+			return syn::Error::new_spanned(fields, "AvroSchema requires named struct fields")
+				.to_compile_error();
+		},
+	};
+
+	let field_exprs = named.iter().map(|field| {
+		let ident = field.ident.as_ref().expect("named field has an ident");
+		let field_name = string_attr(&field.attrs, "rename").unwrap_or_else(|| ident.to_string());
+		let type_expr = avro_type_expr(&field.ty, fullname);
+		quote! {
+			fields.push(::alloc::format!(
+				"{{\"name\":\"{}\",\"type\":{}}}",
+				#field_name,
+				#type_expr
+			));
+		}
+	});
+
+	let ns_fragment = match namespace {
+		Some(ns) => format!(",\"namespace\":\"{}\"", ns),
+		None => String::new(),
+	};
+
+	quote! {{
+		let mut fields: ::alloc::vec::Vec<::alloc::string::String> = ::alloc::vec::Vec::new();
+		#(#field_exprs)*
+		::alloc::format!(
+			"{{\"type\":\"record\",\"name\":\"{}\"{}, \"fields\":[{}]}}",
+			#name,
+			#ns_fragment,
+			fields.join(",")
+		)
+	}}
+}
+
+/// Builds the JSON expression for an enum schema (unit variants only).
+fn enum_json_expr(
+	name: &str,
+	namespace: &Option<String>,
+	data: &syn::DataEnum,
+) -> proc_macro2::TokenStream {
+	for variant in &data.variants {
+		if !matches!(variant.fields, Fields::Unit) {
+			return syn::Error::new_spanned(
+				variant,
+				"AvroSchema enums only support unit variants",
+			)
+			.to_compile_error();
+		}
+	}
+	let symbols = data
+		.variants
+		.iter()
+		.map(|v| format!("\"{}\"", v.ident))
+		.collect::<Vec<_>>()
+		.join(",");
+	let ns_fragment = match namespace {
+		Some(ns) => format!(",\"namespace\":\"{}\"", ns),
+		None => String::new(),
+	};
+	quote! {
+		::alloc::format!(
+			"{{\"type\":\"enum\",\"name\":\"{}\"{}, \"symbols\":[{}]}}",
+			#name,
+			#ns_fragment,
+			#symbols
+		)
+	}
+}
+
+/// Produces an expression of type `String` holding the Avro type JSON for a field type.
+fn avro_type_expr(ty: &Type, enclosing_fullname: &str) -> proc_macro2::TokenStream {
+	if let Some(name) = primitive_avro_type(ty) {
+		let literal = format!("\"{}\"", name);
+		return quote! { ::alloc::string::String::from(#literal) };
+	}
+
+	// `Box<T>` is only indirection (needed to break recursive type cycles); emit `T`.
+	if let Some(inner) = box_inner(ty) {
+		return avro_type_expr(inner, enclosing_fullname);
+	}
+
+	if let Some(inner) = option_inner(ty) {
+		let inner_expr = avro_type_expr(inner, enclosing_fullname);
+		return quote! { ::alloc::format!("[\"null\",{}]", #inner_expr) };
+	}
+
+	if let Some(inner) = vec_inner(ty) {
+		if is_ident(inner, "u8") {
+			return quote! { ::alloc::string::String::from("\"bytes\"") };
+		}
+		let inner_expr = avro_type_expr(inner, enclosing_fullname);
+		return quote! { ::alloc::format!("{{\"type\":\"array\",\"items\":{}}}", #inner_expr) };
+	}
+
+	// Named type: reference the enclosing type by name (handles recursion), otherwise
+	// inline the nested type's own generated schema via its canonical form.
+	if let Type::Path(path) = ty {
+		if let Some(seg) = path.path.segments.last() {
+			if seg.ident == enclosing_fullname.rsplit('.').next().unwrap_or(enclosing_fullname) {
+				let reference = format!("\"{}\"", enclosing_fullname);
+				return quote! { ::alloc::string::String::from(#reference) };
+			}
+		}
+	}
+	quote! { #ty::get_schema().canonical_form() }
+}
+
+/// Returns true when `ty` is a simple path ending in the given identifier.
+fn is_ident(ty: &Type, ident: &str) -> bool {
+	matches!(ty, Type::Path(path) if path.path.segments.last().is_some_and(|s| s.ident == ident))
+}
+
+/// Maps a primitive Rust type to its Avro type name.
+fn primitive_avro_type(ty: &Type) -> Option<&'static str> {
+	let ident = match ty {
+		Type::Path(path) => path.path.segments.last().map(|s| s.ident.to_string())?,
+		_ => return None,
+	};
+	Some(match ident.as_str() {
+		"bool" => "boolean",
+		"i8" | "i16" | "i32" | "u8" | "u16" => "int",
+		"i64" | "u32" | "u64" => "long",
+		"f32" => "float",
+		"f64" => "double",
+		"String" | "str" => "string",
+		_ => return None,
+	})
+}
+
+/// Returns the inner type `T` of an `Option<T>`, if the type is one.
+fn option_inner(ty: &Type) -> Option<&Type> {
+	generic_inner(ty, "Option")
+}
+
+/// Returns the inner type `T` of a `Vec<T>`, if the type is one.
+fn vec_inner(ty: &Type) -> Option<&Type> {
+	generic_inner(ty, "Vec")
+}
+
+/// Returns the inner type `T` of a `Box<T>`, if the type is one.
+fn box_inner(ty: &Type) -> Option<&Type> {
+	generic_inner(ty, "Box")
+}
+
+/// Returns the single generic argument of a `Wrapper<T>` path type.
+fn generic_inner<'a>(ty: &'a Type, wrapper: &str) -> Option<&'a Type> {
+	let Type::Path(path) = ty else { return None };
+	let seg = path.path.segments.last()?;
+	if seg.ident != wrapper {
+		return None;
+	}
+	let PathArguments::AngleBracketed(args) = &seg.arguments else { return None };
+	args.args.iter().find_map(|arg| match arg {
+		GenericArgument::Type(inner) => Some(inner),
+		_ => None,
+	})
+}
+
+/// Extracts a string value from an `#[avro(key = "value")]` attribute.
+fn string_attr(attrs: &[syn::Attribute], key: &str) -> Option<String> {
+	for attr in attrs {
+		if !attr.path().is_ident("avro") {
+			continue;
+		}
+		let mut found = None;
+		let _ = attr.parse_nested_meta(|meta| {
+			if meta.path.is_ident(key) {
+				let value = meta.value()?;
+				let lit: Lit = value.parse()?;
+				if let Lit::Str(s) = lit {
+					found = Some(s.value());
+				}
+			}
+			Ok(())
+		});
+		if found.is_some() {
+			return found;
+		}
+	}
+	None
+}
@@ -0,0 +1,37 @@
+//! Expansion/round-trip test for `#[derive(AvroSchema)]`.
+//!
+//! The generated `get_schema()` emits `::alloc` paths, so the test crate brings
+//! `alloc` into scope to match the companion crate's `no_std` target.
+
+#![allow(dead_code)] // fields exist for their types; the derive reads them, runtime code does not
+
+extern crate alloc;
+
+use apache_avro::schema::Schema;
+use avro_derive::AvroSchema;
+
+#[derive(AvroSchema)]
+#[avro(namespace = "com.example")]
+struct Inner {
+	count: i64,
+}
+
+#[derive(AvroSchema)]
+#[avro(namespace = "com.example")]
+struct Outer {
+	#[avro(rename = "full_name")]
+	name: String,
+	payload: Vec<u8>,
+	inner: Inner,
+	next: Option<Box<Outer>>,
+}
+
+#[test]
+fn derived_schema_parses() {
+	// `get_schema()` parses its own generated JSON, so a bad rename/nested/recursive
+	// emission would panic here rather than returning a record.
+	match Outer::get_schema() {
+		Schema::Record(_) => {},
+		other => panic!("expected a record schema, got {:?}", other),
+	}
+}
@@ -1,11 +1,39 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+//! Avro schema helpers.
+//!
+//! no_std floor: only [`rabin_fingerprint`] (and its precomputed table) is truly
+//! `core`-only. The schema-facing functions — [`canonical_form`],
+//! [`fingerprint_canonical_form`], [`fingerprint_raw_schema`], [`translate_schema`],
+//! [`translate_schemas`], and [`translate_schema_set`] — build on
+//! `apache_avro::schema::Schema`, which depends on `std`; a `--no-default-features`
+//! build of this crate therefore still links `std` transitively through `apache_avro`.
+//! The datum, single-object, schema-resolution, and container APIs are additionally
+//! gated behind the `std` feature, and the parallel list paths behind `parallel`
+//! (rayon). The `alloc`/rayon refactor keeps those functions that could be `no_std`
+//! free of `std`, but the real floor is set by `apache_avro` until it gains no_std
+//! support upstream.
+
+extern crate alloc;
+
 use crate::types::*;
-use apache_avro::{ schema::Schema};
-// use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
-// use std::{collections::HashMap, io::Cursor, str};
+use alloc::{
+	collections::BTreeMap,
+	string::{String, ToString},
+	vec::Vec,
+};
+use apache_avro::schema::Schema;
+use core::str;
+
+#[cfg(feature = "std")]
+use apache_avro::{
+	from_avro_datum, to_avro_datum, types::{Record, Value}, Reader, Writer,
+};
+#[cfg(feature = "std")]
+use std::io::Cursor;
+#[cfg(feature = "parallel")]
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 // use sp_runtime::{traits::One, DispatchError};
-// use sp_std::{collections::btree_map::BTreeMap, convert::TryInto, prelude::*,vec::Vec};
 
 /// Represents error types returned by the `avro` module.
 #[derive(thiserror::Error, Debug)]
@@ -14,9 +42,68 @@ pub enum AvroError {
 	InvalidSchema(String),
 	#[error("Invalid avro records")]
 	InvalidRecords(),
+	#[cfg(feature = "std")]
+	#[error("I/O error: {0}")]
+	Io(#[from] std::io::Error),
+	#[error("Avro error: {0}")]
+	Avro(#[from] apache_avro::Error),
 }
 // pub fn parse_str(raw_schema: &str) {}
 
+/// Function to encode a record against a schema into the Avro binary format.
+/// Returns an error if the schema is not a record or the assembled record does not
+/// validate against it (a missing or mistyped field); fields whose names the schema
+/// does not have are ignored.
+/// # Arguments
+/// * `schema` - the Avro schema to encode against
+/// * `fields` - the record fields as `(name, value)` pairs
+/// # Returns
+/// * `Result<Vec<u8>, AvroError>` - the encoded Avro datum
+/// # Examples
+/// ```
+/// use common_helpers::avro;
+/// use common_helpers::types::*;
+/// let raw_schema = r#"{"type": "record", "name": "User", "fields": [{"name": "name", "type": "string"}]}"#;
+/// let (schema, _) = avro::fingerprint_raw_schema(raw_schema).unwrap();
+/// let fields = vec![("name".to_string(), Value::String("Alice".to_string()))];
+/// let encoded = avro::encode_record(&schema, &fields);
+/// assert!(encoded.is_ok());
+/// ```
+#[cfg(feature = "std")]
+pub fn encode_record(schema: &Schema, fields: &[(String, Value)]) -> Result<Vec<u8>, AvroError> {
+	let mut record = Record::new(schema).ok_or(AvroError::InvalidRecords())?;
+	for (name, value) in fields {
+		record.put(name, value.clone());
+	}
+	let encoded = to_avro_datum(schema, record)?;
+	Ok(encoded)
+}
+
+/// Function to decode an Avro datum against a schema back into a `Value`.
+/// If the bytes are malformed, returns an error.
+/// # Arguments
+/// * `schema` - the Avro schema to decode against
+/// * `bytes` - the encoded Avro datum
+/// # Returns
+/// * `Result<Value, AvroError>` - the decoded value
+/// # Examples
+/// ```
+/// use common_helpers::avro;
+/// use common_helpers::types::*;
+/// let raw_schema = r#"{"type": "record", "name": "User", "fields": [{"name": "name", "type": "string"}]}"#;
+/// let (schema, _) = avro::fingerprint_raw_schema(raw_schema).unwrap();
+/// let fields = vec![("name".to_string(), Value::String("Alice".to_string()))];
+/// let encoded = avro::encode_record(&schema, &fields).unwrap();
+/// let decoded = avro::decode_record(&schema, &encoded);
+/// assert!(decoded.is_ok());
+/// ```
+#[cfg(feature = "std")]
+pub fn decode_record(schema: &Schema, bytes: &[u8]) -> Result<Value, AvroError> {
+	let mut cursor = Cursor::new(bytes);
+	let value = from_avro_datum(schema, &mut cursor, None)?;
+	Ok(value)
+}
+
 /// Function to convert a raw schema into serialized Avro schema.
 /// If schema is malformed or invalid, returns an error.
 /// # Arguments
@@ -38,6 +125,151 @@ pub fn fingerprint_raw_schema(raw_schema: &str) -> Result<(Schema, Vec<u8>), Avr
 	Ok((schema_result, schema_canonical_form.as_bytes().to_vec()))
 }
 
+/// The fingerprint of the empty string, used to seed the CRC-64-AVRO Rabin algorithm.
+const EMPTY_FINGERPRINT: u64 = 0xc15d213aa4d7a795;
+
+/// Precomputes the 256-entry lookup table used by the CRC-64-AVRO Rabin fingerprint.
+const fn build_fingerprint_table() -> [u64; 256] {
+	let mut table = [0u64; 256];
+	let mut i = 0usize;
+	while i < 256 {
+		let mut fp = i as u64;
+		let mut j = 0;
+		while j < 8 {
+			fp = (fp >> 1) ^ (EMPTY_FINGERPRINT & (0u64.wrapping_sub(fp & 1)));
+			j += 1;
+		}
+		table[i] = fp;
+		i += 1;
+	}
+	table
+}
+
+/// The CRC-64-AVRO Rabin fingerprint lookup table.
+const FINGERPRINT_TABLE: [u64; 256] = build_fingerprint_table();
+
+/// Function to compute the 64-bit CRC-64-AVRO Rabin fingerprint of a byte slice.
+/// This is the fingerprint algorithm defined by the Avro specification.
+/// # Arguments
+/// * `bytes` - the bytes to fingerprint (typically a schema's Parsing Canonical Form)
+/// # Returns
+/// * `u64` - the Rabin fingerprint
+/// # Examples
+/// ```
+/// use common_helpers::avro;
+/// // CRC-64-AVRO fingerprint of the canonical form of a `"string"` schema.
+/// assert_eq!(avro::rabin_fingerprint(br#""string""#), 0x8f014872634503c7);
+/// ```
+pub fn rabin_fingerprint(bytes: &[u8]) -> u64 {
+	let mut fp = EMPTY_FINGERPRINT;
+	for b in bytes {
+		fp = (fp >> 8) ^ FINGERPRINT_TABLE[((fp ^ *b as u64) & 0xff) as usize];
+	}
+	fp
+}
+
+/// Function to return the Parsing Canonical Form of a raw schema.
+/// If schema is malformed or invalid, returns an error.
+/// # Arguments
+/// * `raw_schema` - raw schema to be converted
+/// # Returns
+/// * `Result<String, AvroError>` - the Parsing Canonical Form
+/// # Examples
+/// ```
+/// use common_helpers::avro;
+/// let form = avro::canonical_form(r#"{"type": "string"}"#).unwrap();
+/// assert_eq!(form, r#""string""#);
+/// ```
+pub fn canonical_form(raw_schema: &str) -> Result<String, AvroError> {
+	let schema = Schema::parse_str(raw_schema)?;
+	Ok(schema.canonical_form())
+}
+
+/// Function to compute the CRC-64-AVRO Rabin fingerprint of a schema.
+/// The fingerprint is taken over the schema's Parsing Canonical Form.
+/// # Arguments
+/// * `schema` - the Avro schema to fingerprint
+/// # Returns
+/// * `(u64, [u8; 8])` - the fingerprint as a `u64` and its little-endian byte form
+/// # Examples
+/// ```
+/// use common_helpers::avro;
+/// let (schema, _) = avro::fingerprint_raw_schema(r#"{"type": "string"}"#).unwrap();
+/// let (fp, bytes) = avro::fingerprint_canonical_form(&schema);
+/// assert_eq!(fp, 0x8f014872634503c7);
+/// assert_eq!(bytes, fp.to_le_bytes());
+/// ```
+pub fn fingerprint_canonical_form(schema: &Schema) -> (u64, [u8; 8]) {
+	let fp = rabin_fingerprint(schema.canonical_form().as_bytes());
+	(fp, fp.to_le_bytes())
+}
+
+/// Function to encode a value using Avro Single Object Encoding.
+/// The output is the two-byte marker `0xC3 0x01`, followed by the 8-byte
+/// little-endian CRC-64-AVRO fingerprint of the schema, followed by the
+/// Avro binary body, so the message is self-describing by schema fingerprint.
+/// # Arguments
+/// * `schema` - the Avro schema the value conforms to
+/// * `value` - the value to encode
+/// # Returns
+/// * `Result<Vec<u8>, AvroError>` - the single-object-encoded message
+/// # Examples
+/// ```
+/// use common_helpers::avro;
+/// use common_helpers::types::*;
+/// let (schema, _) = avro::fingerprint_raw_schema(r#"{"type": "string"}"#).unwrap();
+/// let encoded = avro::encode_single_object(&schema, Value::String("hi".to_string())).unwrap();
+/// assert_eq!(&encoded[..2], &[0xC3, 0x01]);
+/// ```
+#[cfg(feature = "std")]
+pub fn encode_single_object(schema: &Schema, value: Value) -> Result<Vec<u8>, AvroError> {
+	let (_, fingerprint) = fingerprint_canonical_form(schema);
+	let body = to_avro_datum(schema, value)?;
+	let mut out = Vec::with_capacity(2 + fingerprint.len() + body.len());
+	out.push(0xC3);
+	out.push(0x01);
+	out.extend_from_slice(&fingerprint);
+	out.extend_from_slice(&body);
+	Ok(out)
+}
+
+/// Function to decode a message produced by [`encode_single_object`].
+/// Validates the marker, reads the fingerprint, and looks the schema up in the
+/// caller-supplied registry before decoding the body.
+/// # Arguments
+/// * `bytes` - the single-object-encoded message
+/// * `schema_registry` - a map from schema fingerprint to `Schema`
+/// # Returns
+/// * `Result<Value, AvroError>` - the decoded value, or `InvalidRecords` when the
+///   marker is absent or the fingerprint is unknown
+/// # Examples
+/// ```
+/// use common_helpers::avro;
+/// use common_helpers::types::*;
+/// use std::collections::BTreeMap;
+/// let (schema, _) = avro::fingerprint_raw_schema(r#"{"type": "string"}"#).unwrap();
+/// let (fp, _) = avro::fingerprint_canonical_form(&schema);
+/// let encoded = avro::encode_single_object(&schema, Value::String("hi".to_string())).unwrap();
+/// let mut registry = BTreeMap::new();
+/// registry.insert(fp, schema);
+/// let decoded = avro::decode_single_object(&encoded, &registry).unwrap();
+/// assert_eq!(decoded, Value::String("hi".to_string()));
+/// ```
+#[cfg(feature = "std")]
+pub fn decode_single_object(
+	bytes: &[u8],
+	schema_registry: &BTreeMap<u64, Schema>,
+) -> Result<Value, AvroError> {
+	if bytes.len() < 10 || bytes[0] != 0xC3 || bytes[1] != 0x01 {
+		return Err(AvroError::InvalidRecords());
+	}
+	let mut fingerprint_bytes = [0u8; 8];
+	fingerprint_bytes.copy_from_slice(&bytes[2..10]);
+	let fingerprint = u64::from_le_bytes(fingerprint_bytes);
+	let schema = schema_registry.get(&fingerprint).ok_or(AvroError::InvalidRecords())?;
+	decode_record(schema, &bytes[10..])
+}
+
 /// Function to convert a list of raw schema into serialized Avro schema.
 /// If schema is malformed or invalid, it is set to Null.
 /// # Arguments
@@ -57,8 +289,12 @@ pub fn fingerprint_raw_schema(raw_schema: &str) -> Result<(Schema, Vec<u8>), Avr
 pub fn fingerprint_raw_schema_list(
 	raw_schema: &[&str],
 ) -> Result<(Vec<Schema>, Vec<Vec<u8>>), AvroError> {
-	let schemas: (Vec<Schema>, Vec<Vec<u8>>) = raw_schema
-		.par_iter()
+	#[cfg(feature = "parallel")]
+	let iter = raw_schema.par_iter();
+	#[cfg(not(feature = "parallel"))]
+	let iter = raw_schema.iter();
+
+	let schemas: (Vec<Schema>, Vec<Vec<u8>>) = iter
 		.map(|r| -> (Schema, Vec<u8>) {
 			let schema = fingerprint_raw_schema(r);
 			match schema {
@@ -118,8 +354,12 @@ pub fn translate_schema(serialized_schema: Vec<u8>) -> Result<Schema, AvroError>
 /// assert!(translated_schema.is_ok());
 /// ```
 pub fn translate_schemas(serialized_schema: Vec<Vec<u8>>) -> Result<Vec<Schema>, AvroError> {
-	let schemas: Vec<Schema> = serialized_schema
-		.par_iter()
+	#[cfg(feature = "parallel")]
+	let iter = serialized_schema.par_iter();
+	#[cfg(not(feature = "parallel"))]
+	let iter = serialized_schema.iter();
+
+	let schemas: Vec<Schema> = iter
 		.map(|o| -> Schema {
 			let schema = translate_schema(o.to_vec());
 			match schema {
@@ -130,4 +370,210 @@ pub fn translate_schemas(serialized_schema: Vec<Vec<u8>>) -> Result<Vec<Schema>,
 		.collect();
 
 	Ok(schemas)
-}
\ No newline at end of file
+}
+
+/// Function to decode Avro data written with one schema using a (possibly
+/// different) reader schema, performing Avro schema resolution between them.
+/// This supports reading data written with an older or newer schema.
+/// # Arguments
+/// * `writer_schema` - the schema the data was written with
+/// * `reader_schema` - the schema to resolve the data into
+/// * `bytes` - the encoded Avro datum
+/// # Returns
+/// * `Result<Value, AvroError>` - the resolved value
+#[cfg(feature = "std")]
+pub fn resolve_and_decode(
+	writer_schema: &Schema,
+	reader_schema: &Schema,
+	bytes: &[u8],
+) -> Result<Value, AvroError> {
+	let mut cursor = Cursor::new(bytes);
+	let value = from_avro_datum(writer_schema, &mut cursor, Some(reader_schema))?;
+	Ok(value)
+}
+
+/// Function to parse a set of named raw schemas together so that cross-references
+/// between them resolve correctly, keyed by each schema's fullname.
+/// Unlike [`translate_schemas`], which treats each schema in isolation, this
+/// parses the whole set as a unit.
+/// # Arguments
+/// * `raw_schemas` - the raw schemas to parse together
+/// # Returns
+/// * `Result<BTreeMap<String, Schema>, AvroError>` - named schemas keyed by fullname
+pub fn translate_schema_set(raw_schemas: &[&str]) -> Result<BTreeMap<String, Schema>, AvroError> {
+	let schemas = Schema::parse_list(raw_schemas)?;
+	let mut set = BTreeMap::new();
+	for schema in schemas {
+		if let Some(name) = schema.name() {
+			set.insert(name.fullname(None), schema);
+		}
+	}
+	Ok(set)
+}
+/// Compression codecs for the Avro object container file format.
+///
+/// Mirrors the codecs supported by the upstream `apache_avro` crate. `Null` and
+/// `Deflate` are always available; the heavier codecs are gated behind cargo
+/// features so the minimal build stays lean. Each gating feature must forward to
+/// the matching `apache_avro` feature in this crate's manifest, e.g.
+/// `snappy = ["apache_avro/snappy"]`, `zstandard = ["apache_avro/zstandard"]`,
+/// `bzip = ["apache_avro/bzip"]`; otherwise the corresponding `apache_avro::Codec`
+/// variant is not compiled and `to_avro` fails to build.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Codec {
+	/// No compression.
+	Null,
+	/// Deflate (zlib) compression.
+	Deflate,
+	/// Snappy compression.
+	#[cfg(feature = "snappy")]
+	Snappy,
+	/// Zstandard compression.
+	#[cfg(feature = "zstandard")]
+	Zstandard,
+	/// Bzip2 compression.
+	#[cfg(feature = "bzip")]
+	Bzip2,
+}
+
+#[cfg(feature = "std")]
+impl Codec {
+	/// Maps this crate's codec enum onto the `apache_avro` codec.
+	fn to_avro(self) -> apache_avro::Codec {
+		match self {
+			Codec::Null => apache_avro::Codec::Null,
+			Codec::Deflate => apache_avro::Codec::Deflate,
+			#[cfg(feature = "snappy")]
+			Codec::Snappy => apache_avro::Codec::Snappy,
+			#[cfg(feature = "zstandard")]
+			Codec::Zstandard => apache_avro::Codec::Zstandard,
+			#[cfg(feature = "bzip")]
+			Codec::Bzip2 => apache_avro::Codec::Bzip2,
+		}
+	}
+}
+
+/// Function to write records into an Avro object container file.
+/// The schema is embedded in the file header along with the chosen codec.
+/// # Arguments
+/// * `schema` - the schema all records conform to
+/// * `records` - the records to write
+/// * `codec` - the compression codec to apply to each block
+/// # Returns
+/// * `Result<Vec<u8>, AvroError>` - the encoded container file
+/// # Examples
+/// ```
+/// use common_helpers::avro;
+/// use common_helpers::types::*;
+/// let (schema, _) = avro::fingerprint_raw_schema(r#"{"type": "string"}"#).unwrap();
+/// let records = vec![Value::String("a".to_string()), Value::String("b".to_string())];
+/// let bytes = avro::write_container(&schema, &records, avro::Codec::Null).unwrap();
+/// let read = avro::read_container(&bytes).unwrap();
+/// assert_eq!(read, records);
+/// ```
+#[cfg(feature = "std")]
+pub fn write_container(
+	schema: &Schema,
+	records: &[Value],
+	codec: Codec,
+) -> Result<Vec<u8>, AvroError> {
+	let mut writer = Writer::with_codec(schema, Vec::new(), codec.to_avro());
+	for record in records {
+		writer.append(record.clone())?;
+	}
+	let encoded = writer.into_inner()?;
+	Ok(encoded)
+}
+
+/// Function to read records from an Avro object container file.
+/// The schema and codec are taken from the file header.
+/// # Arguments
+/// * `bytes` - the encoded container file
+/// # Returns
+/// * `Result<Vec<Value>, AvroError>` - the decoded records
+/// # Examples
+/// ```
+/// use common_helpers::avro;
+/// use common_helpers::types::*;
+/// let (schema, _) = avro::fingerprint_raw_schema(r#"{"type": "string"}"#).unwrap();
+/// let bytes = avro::write_container(&schema, &[Value::String("a".to_string())], avro::Codec::Null).unwrap();
+/// let read = avro::read_container(&bytes).unwrap();
+/// assert_eq!(read.len(), 1);
+/// ```
+#[cfg(feature = "std")]
+pub fn read_container(bytes: &[u8]) -> Result<Vec<Value>, AvroError> {
+	let reader = Reader::new(Cursor::new(bytes))?;
+	let mut values = Vec::new();
+	for value in reader {
+		values.push(value?);
+	}
+	Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn rabin_fingerprint_matches_spec_vector() {
+		// Known-answer vector from the Avro spec: the CRC-64-AVRO fingerprint of the
+		// canonical form of a `"string"` schema. Pins the shift/mask constants so a
+		// subtle regression in the table or update step is caught.
+		assert_eq!(rabin_fingerprint(br#""string""#), 0x8f014872634503c7);
+	}
+
+	#[test]
+	fn fingerprint_canonical_form_fingerprints_canonical_form() {
+		let (schema, _) = fingerprint_raw_schema(r#"{"type": "string"}"#).unwrap();
+		let (fp, bytes) = fingerprint_canonical_form(&schema);
+		assert_eq!(fp, 0x8f014872634503c7);
+		assert_eq!(bytes, fp.to_le_bytes());
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn single_object_round_trips() {
+		let (schema, _) = fingerprint_raw_schema(r#"{"type": "string"}"#).unwrap();
+		let (fp, _) = fingerprint_canonical_form(&schema);
+		let encoded = encode_single_object(&schema, Value::String("hi".to_string())).unwrap();
+		assert_eq!(&encoded[..2], &[0xC3, 0x01]);
+		let mut registry = BTreeMap::new();
+		registry.insert(fp, schema);
+		let decoded = decode_single_object(&encoded, &registry).unwrap();
+		assert_eq!(decoded, Value::String("hi".to_string()));
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn single_object_rejects_short_buffer() {
+		let registry: BTreeMap<u64, Schema> = BTreeMap::new();
+		assert!(matches!(
+			decode_single_object(&[0xC3, 0x01, 0x00], &registry),
+			Err(AvroError::InvalidRecords())
+		));
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn single_object_rejects_unknown_fingerprint() {
+		let (schema, _) = fingerprint_raw_schema(r#"{"type": "string"}"#).unwrap();
+		let encoded = encode_single_object(&schema, Value::String("hi".to_string())).unwrap();
+		// Registry intentionally left empty so the fingerprint is unknown.
+		let registry: BTreeMap<u64, Schema> = BTreeMap::new();
+		assert!(matches!(
+			decode_single_object(&encoded, &registry),
+			Err(AvroError::InvalidRecords())
+		));
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn container_round_trips() {
+		let (schema, _) = fingerprint_raw_schema(r#"{"type": "string"}"#).unwrap();
+		let records = vec![Value::String("a".to_string()), Value::String("b".to_string())];
+		let bytes = write_container(&schema, &records, Codec::Null).unwrap();
+		let read = read_container(&bytes).unwrap();
+		assert_eq!(read, records);
+	}
+}